@@ -0,0 +1,60 @@
+//! A dynamics-driven third-person follow camera.
+
+mod common;
+
+use bevy::prelude::*;
+
+use pair::{DynamicCamera, DynamicCameraPlugin, SecondOrderDynamics, SecondOrderRotation};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(common::Plugin)
+        .add_plugins(DynamicCameraPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, orbit_subject)
+        .run();
+}
+
+/// The entity the camera is chasing.
+#[derive(Component)]
+struct Subject;
+
+const BOOM: Vec3 = Vec3::new(0.0, 3.0, -8.0);
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let subject = commands
+        .spawn((
+            Subject,
+            PbrBundle {
+                mesh: meshes.add(Cuboid::from_size(Vec3::splat(1.2)).mesh()),
+                material: materials.add(Color::rgb(0.8, 0.7, 0.6)),
+                ..default()
+            },
+        ))
+        .id();
+
+    commands.spawn((
+        Name::new("Camera dynamics settings"),
+        Camera3dBundle::default(),
+        DynamicCamera::new(
+            subject,
+            BOOM,
+            SecondOrderDynamics::new(2.0, 0.6, 2.0, BOOM),
+            SecondOrderRotation::new(2.0, 1.0, 0.0, Quat::IDENTITY),
+        ),
+    ));
+}
+
+/// Drives the subject in a circle so the camera has something to chase.
+fn orbit_subject(time: Res<Time>, mut subjects: Query<&mut Transform, With<Subject>>) {
+    let t = time.elapsed_seconds();
+    for mut transform in &mut subjects {
+        transform.translation = Vec3::new(t.cos() * 6.0, 1.0, t.sin() * 6.0);
+        transform.rotation = Quat::from_rotation_y(t);
+    }
+}
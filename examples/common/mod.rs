@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPlugin};
 
-use crate::Dynamic;
+use pair::{Follow, SecondOrderDynamics, SecondOrderRotation};
 
 pub struct Plugin;
 
@@ -13,16 +13,41 @@ impl bevy::app::Plugin for Plugin {
     }
 }
 
-pub fn update_dynamics(mut contexts: EguiContexts, mut dynamics: Query<(DebugName, &mut Dynamic)>) {
-    for (name, mut dynamic) in dynamics.iter_mut() {
+/// Live f/ζ/r tuning for any [`Follow`], including its optional rotation dynamics.
+pub fn update_dynamics(mut contexts: EguiContexts, mut follows: Query<(DebugName, &mut Follow)>) {
+    for (name, mut follow) in follows.iter_mut() {
         egui::Window::new(format!("{:?}", name)).show(contexts.ctx_mut(), |ui| {
+            let mut f = follow.position.f;
+            let mut z = follow.position.z;
+            let mut r = follow.position.r;
+
             let response = ui
-                .add(egui::Slider::new(&mut dynamic.f, 0.0..=10.0).text("f (frequency)"))
-                | ui.add(egui::Slider::new(&mut dynamic.z, 0.0..=10.0).text("ζ (damping)"))
-                | ui.add(egui::Slider::new(&mut dynamic.r, -10.0..=10.0).text("r (response)"));
+                .add(egui::Slider::new(&mut f, 0.0..=10.0).text("f (frequency)"))
+                | ui.add(egui::Slider::new(&mut z, 0.0..=10.0).text("ζ (damping)"))
+                | ui.add(egui::Slider::new(&mut r, -10.0..=10.0).text("r (response)"));
 
             if response.changed() {
-                *dynamic = Dynamic::new(dynamic.f, dynamic.z, dynamic.r);
+                let (y, yd) = (follow.position.y, follow.position.velocity());
+                follow.position = SecondOrderDynamics::new(f, z, r, y);
+                follow.position.set_state(y, yd);
+            }
+
+            if let Some(rotation) = follow.rotation.as_mut() {
+                let mut f = rotation.f;
+                let mut z = rotation.z;
+                let mut r = rotation.r;
+
+                let response = ui
+                    .add(egui::Slider::new(&mut f, 0.0..=10.0).text("rotation f (frequency)"))
+                    | ui.add(egui::Slider::new(&mut z, 0.0..=10.0).text("rotation ζ (damping)"))
+                    | ui.add(egui::Slider::new(&mut r, -10.0..=10.0).text("rotation r (response)"));
+
+                if response.changed() {
+                    let (y, yd) = (rotation.y, rotation.angular_velocity());
+                    let mut rebuilt = SecondOrderRotation::new(f, z, r, y);
+                    rebuilt.set_state(y, yd);
+                    *rotation = rebuilt;
+                }
             }
         });
     }
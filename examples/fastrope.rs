@@ -1,12 +1,17 @@
-//! A simple rope. This is a rehash of this tweet: https://x.com/t3ssel8r/status/1470039981502922752
+//! A rope with a dynamics-driven node at each interior segment boundary. This is a
+//! rehash of this tweet:
+//! https://x.com/t3ssel8r/status/1470039981502922752
 
 mod common;
 
-use bevy::prelude::*;
+use bevy::{
+    math::cubic_splines::{CubicCardinalSpline, CubicGenerator},
+    prelude::*,
+};
 use bevy_mod_picking::DefaultPickingPlugins;
 use bevy_transform_gizmo::*;
 
-use common::Dynamics;
+use pair::SecondOrderDynamics;
 
 fn main() {
     App::new()
@@ -18,18 +23,40 @@ fn main() {
         .run();
 }
 
-/// A rope.
+/// A rope with a dynamics node at each interior segment boundary. `start` is read
+/// from the rope entity's own `GlobalTransform` each frame (it's a child of the
+/// first cube); `end` is the attach point at the other end.
 #[derive(Component, Debug)]
 struct Rope {
+    segments: usize,
     length: f32,
-    midpoint: Entity,
     end: Entity,
+    nodes: Vec<SecondOrderDynamics<Vec3>>,
 }
 
 #[derive(Component)]
 struct Point;
 
 const CUBE_SIZE: f32 = 1.2;
+const SEGMENTS: usize = 8;
+
+impl Rope {
+    fn new(segments: usize, length: f32, end: Entity, start: Vec3, end_pos: Vec3, f: f32, z: f32, r: f32) -> Self {
+        let nodes = (1..segments)
+            .map(|i| {
+                let u = i as f32 / segments as f32;
+                SecondOrderDynamics::new(f, z, r, start.lerp(end_pos, u))
+            })
+            .collect();
+
+        Self {
+            segments,
+            length,
+            end,
+            nodes,
+        }
+    }
+}
 
 fn setup(
     mut commands: Commands,
@@ -38,16 +65,8 @@ fn setup(
 ) {
     let start = Vec3::new(-3.0, 6.0, 0.0);
     let end = Vec3::new(3.0, 6.0, 0.0);
-    let midpoint = (start + end) * 0.5;
     let local_offset = Vec3::X * CUBE_SIZE * 0.5;
 
-    let midpoint_id = commands
-        .spawn((
-            Point,
-            SpatialBundle::from_transform(Transform::from_translation(midpoint)),
-        ))
-        .id();
-
     // The endpoint will be attached to the left side of the second cube.
     let end_id = commands
         .spawn((
@@ -71,13 +90,8 @@ fn setup(
         .with_children(|p| {
             // The rope is attached to the side of the first cube.
             p.spawn((
-                Rope {
-                    length: 8.0,
-                    midpoint: midpoint_id,
-                    end: end_id,
-                },
+                Rope::new(SEGMENTS, 8.0, end_id, start + local_offset, end, 3.0, 0.5, 2.0),
                 Name::new("Rope settings"),
-                Dynamics::new(3.0, 0.5, 2.0, midpoint),
                 SpatialBundle::from_transform(Transform::from_translation(local_offset)),
             ));
         });
@@ -99,12 +113,6 @@ fn setup(
     // camera
     commands.spawn((
         Camera3dBundle {
-            // projection: OrthographicProjection {
-            //     scale: 10.0,
-            //     scaling_mode: ScalingMode::FixedVertical(2.0),
-            //     ..default()
-            // }
-            // .into(),
             transform: Transform::from_xyz(0.0, 18.0, 16.0)
                 .looking_at(Vec3::new(0.0, 6.0, 0.0), Vec3::Y),
             ..default()
@@ -113,49 +121,46 @@ fn setup(
     ));
 }
 
-/// Draw a rope between two points.
+/// Draw a rope through all of its dynamics nodes.
 fn update_rope(
     time: Res<Time>,
-    mut ropes: Query<(&Rope, &GlobalTransform, &mut Dynamics), Without<Point>>,
-    mut points: Query<(&GlobalTransform, &mut Transform), With<Point>>,
+    mut ropes: Query<(&mut Rope, &GlobalTransform), Without<Point>>,
+    points: Query<&GlobalTransform, With<Point>>,
     mut gizmos: Gizmos,
 ) {
     let dt = time.delta_seconds();
 
-    for (rope, start, mut dynamic) in ropes.iter_mut() {
-        let start = start.translation();
-        let end = points
-            .get(rope.end)
-            .expect("endpoint exists")
-            .0
-            .translation();
-        let (_, mut mid_t) = points.get_mut(rope.midpoint).expect("midpoint exists");
+    for (mut rope, rope_transform) in ropes.iter_mut() {
+        let start = rope_transform.translation();
+        let end = points.get(rope.end).expect("endpoint exists").translation();
 
-        let midpoint = (start + end) * 0.5;
-        let slack = rope.length - start.distance(end);
-        let drop = midpoint - Vec3::new(0.0, slack.max(0.0), 0.0);
-
-        // The red lines display a fixed midpoint and drop point, which depends on the slack in the rope.
         gizmos.line(start, end, Color::RED);
-        gizmos.line(midpoint, drop, Color::RED);
-        gizmos.circle(drop, Direction3d::Y, 0.1, Color::RED);
-
-        // Technically, we don't need to update the midpoint to draw the rope, but it's there if you
-        // want to attach something to it.
-        if dt > 0.0 {
-            mid_t.translation = dynamic.state.update(dt, drop, None);
-            let bezier = raise(start, mid_t.translation, end);
-            gizmos.linestrip(bezier.to_curve().iter_positions(64), Color::BLACK);
+
+        if dt <= 0.0 {
+            continue;
         }
-    }
-}
 
-// Make a cubic bezier from a quadratic.
-fn raise(p0: Vec3, p1: Vec3, p2: Vec3) -> CubicBezier<Vec3> {
-    CubicBezier::new([[
-        p0,
-        p0 + (2.0 / 3.0) * (p1 - p0),
-        p2 + (2.0 / 3.0) * (p1 - p2),
-        p2,
-    ]])
+        // Remaining slack in the rope beyond the straight-line distance between the
+        // endpoints; sags the most in the middle and nothing at the ends.
+        let slack = (rope.length - start.distance(end)).max(0.0);
+        let segments = rope.segments as f32;
+
+        let mut positions = Vec::with_capacity(rope.segments + 1);
+        positions.push(start);
+
+        for (i, node) in rope.nodes.iter_mut().enumerate() {
+            let u = (i + 1) as f32 / segments;
+            let straight = start.lerp(end, u);
+            let sag = slack * 4.0 * u * (1.0 - u);
+            let target = straight - Vec3::Y * sag;
+
+            gizmos.circle(target, Direction3d::Y, 0.1, Color::RED);
+            positions.push(node.update(dt, target, None));
+        }
+
+        positions.push(end);
+
+        let curve = CubicCardinalSpline::new_catmull_rom(positions).to_curve();
+        gizmos.linestrip(curve.iter_positions(16 * rope.segments as u32), Color::BLACK);
+    }
 }
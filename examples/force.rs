@@ -0,0 +1,138 @@
+//! Driving a body with dynamics-as-force instead of overwriting its `Transform`.
+//!
+//! The other examples write `dynamics.update(...)` straight into
+//! `Transform.translation`, which fights any physics engine and skips collisions
+//! entirely. Here the integrator only supplies a desired acceleration; a stand-in
+//! `ExternalForce` component (shaped like the one avian3d and bevy_rapier3d both
+//! expose) applies it as a real force, while a stand-in solver below still owns the
+//! final position and velocity.
+
+mod common;
+
+use bevy::prelude::*;
+
+use pair::SecondOrderDynamics;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(common::Plugin)
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (orbit_target, apply_dynamics_force, integrate_body).chain(),
+        )
+        .run();
+}
+
+/// Stand-in for a physics backend's per-body external force accumulator
+/// (`avian3d`'s and `bevy_rapier3d`'s `ExternalForce` both expose this shape).
+#[derive(Component, Default)]
+struct ExternalForce {
+    force: Vec3,
+}
+
+/// Stand-in for the body's true linear velocity, as tracked by the physics solver.
+#[derive(Component, Default)]
+struct LinearVelocity(Vec3);
+
+#[derive(Component)]
+struct Target;
+
+#[derive(Component)]
+struct Body {
+    dynamics: SecondOrderDynamics<Vec3>,
+    mass: f32,
+}
+
+const TARGET_POS: Vec3 = Vec3::new(0.0, 3.0, 0.0);
+const BODY_POS: Vec3 = Vec3::new(3.0, 3.0, 0.0);
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // target
+    commands.spawn((
+        Target,
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(0.5).mesh().ico(4).unwrap()),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6)),
+            transform: Transform::from_translation(TARGET_POS),
+            ..default()
+        },
+    ));
+
+    // body, driven by force instead of a transform overwrite
+    commands.spawn((
+        Name::new("Force dynamics settings"),
+        Body {
+            dynamics: SecondOrderDynamics::new(2.0, 0.5, 2.0, BODY_POS),
+            mass: 1.0,
+        },
+        ExternalForce::default(),
+        LinearVelocity::default(),
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(1.0).mesh().ico(5).unwrap()),
+            material: materials.add(Color::rgb(0.2, 0.7, 0.6)),
+            transform: Transform::from_translation(BODY_POS),
+            ..default()
+        },
+    ));
+
+    // camera
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 10.0, 14.0)
+            .looking_at(Vec3::new(0.0, 3.0, 0.0), Vec3::Y),
+        ..default()
+    });
+}
+
+/// Moves the target around in a circle so the body has something to chase.
+fn orbit_target(time: Res<Time>, mut targets: Query<&mut Transform, With<Target>>) {
+    let t = time.elapsed_seconds();
+    for mut transform in &mut targets {
+        transform.translation = TARGET_POS + Vec3::new(t.sin() * 3.0, 0.0, t.cos() * 3.0);
+    }
+}
+
+/// Computes the dynamics acceleration toward the target and writes it out as a
+/// force, seeding the dynamics state from the body's actual transform and velocity
+/// first (the "previous velocity" pattern) so the solver, not the dynamics, owns
+/// the real position.
+fn apply_dynamics_force(
+    time: Res<Time>,
+    targets: Query<&Transform, With<Target>>,
+    mut bodies: Query<(&Transform, &LinearVelocity, &mut Body, &mut ExternalForce)>,
+) {
+    let dt = time.delta_seconds();
+    if dt == 0.0 {
+        return;
+    }
+
+    let Some(target) = targets.iter().next() else {
+        return;
+    };
+
+    for (transform, velocity, mut body, mut force) in &mut bodies {
+        body.dynamics.set_state(transform.translation, velocity.0);
+        let a = body.dynamics.acceleration(dt, target.translation, None);
+        force.force = a * body.mass;
+    }
+}
+
+/// Stand-in for what a real physics solver does: integrate the applied force into
+/// velocity and position (and, with a real backend, resolve collisions along the
+/// way).
+fn integrate_body(
+    time: Res<Time>,
+    mut bodies: Query<(&mut Transform, &mut LinearVelocity, &ExternalForce, &Body)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut transform, mut velocity, force, body) in &mut bodies {
+        velocity.0 += force.force / body.mass * dt;
+        transform.translation += velocity.0 * dt;
+    }
+}
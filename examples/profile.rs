@@ -0,0 +1,65 @@
+//! Loading f/ζ/r tuning from a `.dynamics.ron` asset, with hot reload.
+//!
+//! Edit `assets/dynamics/snappy.dynamics.ron` while this example is running and the
+//! follower retunes instantly, no recompile required.
+
+mod common;
+
+use bevy::prelude::*;
+
+use pair::{DynamicsProfileHandle, DynamicsProfilePlugin, Follow, FollowPlugin, FollowTarget, SecondOrderDynamics};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(common::Plugin)
+        .add_plugins((FollowPlugin, DynamicsProfilePlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+#[derive(Component)]
+struct Tracking;
+
+const TRACKING_POS: Vec3 = Vec3::new(-3.0, 3.0, 0.0);
+const FOLLOWER_POS: Vec3 = Vec3::new(3.0, 3.0, 0.0);
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    let tracking = commands
+        .spawn((
+            Tracking,
+            PbrBundle {
+                mesh: meshes.add(Sphere::new(0.8).mesh().ico(5).unwrap()),
+                material: materials.add(Color::rgb(0.8, 0.7, 0.6)),
+                transform: Transform::from_translation(TRACKING_POS),
+                ..default()
+            },
+        ))
+        .id();
+
+    commands.spawn((
+        Name::new("Profile-driven follower"),
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(1.0).mesh().ico(5).unwrap()),
+            material: materials.add(Color::rgb(0.2, 0.7, 0.6)),
+            transform: Transform::from_translation(FOLLOWER_POS),
+            ..default()
+        },
+        DynamicsProfileHandle(asset_server.load("dynamics/snappy.dynamics.ron")),
+        Follow::new(
+            FollowTarget::Entity(tracking),
+            SecondOrderDynamics::new(4.0, 0.6, 2.0, FOLLOWER_POS),
+        ),
+    ));
+
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 10.0, 14.0)
+            .looking_at(Vec3::new(0.0, 3.0, 0.0), Vec3::Y),
+        ..default()
+    });
+}
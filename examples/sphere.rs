@@ -4,21 +4,20 @@ mod common;
 
 use bevy::{input::mouse::MouseMotion, prelude::*, render::camera::ScalingMode};
 
-use common::Dynamics;
+use pair::{Follow, FollowPlugin, FollowTarget, SecondOrderDynamics};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(common::Plugin)
+        .add_plugins(FollowPlugin)
         .add_systems(Startup, setup)
         .add_systems(
             Update,
             (
                 // track_motion,
                 track_cursor,
-                update_dynamic,
-            )
-                .chain(),
+            ),
         )
         .run();
 }
@@ -27,10 +26,6 @@ fn main() {
 #[derive(Component)]
 struct Tracking;
 
-/// Velocity component.
-#[derive(Component, Default)]
-struct Velocity(Vec3);
-
 // Offset the two objects so we can see the difference in motion.
 const TRACKING_POS: Vec3 = Vec3::new(-3.0, 3.0, 0.0);
 const DYNAMIC_POS: Vec3 = Vec3::new(3.0, 3.0, 0.0);
@@ -42,14 +37,15 @@ fn setup(
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
     // tracking object
-    commands
+    let tracking = commands
         .spawn(PbrBundle {
             mesh: meshes.add(Sphere::new(0.8).mesh().ico(5).unwrap()),
             material: materials.add(Color::rgb(0.8, 0.7, 0.6)),
             transform: Transform::from_translation(TRACKING_POS),
             ..default()
         })
-        .insert((Tracking, Velocity::default()));
+        .insert(Tracking)
+        .id();
 
     // dynamics object
     commands.spawn((
@@ -60,8 +56,13 @@ fn setup(
             transform: Transform::from_translation(DYNAMIC_POS),
             ..default()
         },
-        // The dynamics are tracking the tracker internally. The offset is added post-update.
-        Dynamics::new(2.5, 1.0, 1.0, DYNAMIC_POS),
+        // The dynamics track the tracker entity internally; the offset is added
+        // post-update by FollowPlugin.
+        Follow::new(
+            FollowTarget::Entity(tracking),
+            SecondOrderDynamics::new(2.5, 1.0, 1.0, DYNAMIC_POS),
+        )
+        .with_offset(DYNAMIC_OFFSET),
     ));
 
     // camera
@@ -81,12 +82,10 @@ fn setup(
 /// Tracks mouse motion and updates the tracking object.
 #[allow(unused)]
 fn track_motion(
-    time: Res<Time>,
     mut mouse_events: EventReader<MouseMotion>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
-    mut query: Query<(&mut Transform, &mut Velocity), With<Tracking>>,
+    mut query: Query<&mut Transform, With<Tracking>>,
 ) {
-    let dt = time.delta_seconds();
     let delta = if mouse_button_input.pressed(MouseButton::Left) {
         let d: Vec2 = mouse_events.read().map(|m| m.delta).sum();
         let d = d * Vec2::new(1.1, 2.0) * 0.018;
@@ -98,26 +97,19 @@ fn track_motion(
     };
 
     if let Some(d) = delta {
-        for (mut t, mut v) in query.iter_mut() {
-            // Save target/velocity.
-            let target = t.translation + d;
-            v.0 = if dt > 0.0 { d / dt } else { Vec3::ZERO };
-            t.translation = target;
+        for mut t in query.iter_mut() {
+            t.translation += d;
         }
     };
 }
 
 /// Moves the tracking object to the cursor location.
-#[allow(unused)]
 fn track_cursor(
-    time: Res<Time>,
     mut cursor_moved: EventReader<CursorMoved>,
     cameras: Query<(&Camera, &GlobalTransform)>,
-    mut query: Query<(&mut Transform, &mut Velocity), With<Tracking>>,
+    mut query: Query<&mut Transform, With<Tracking>>,
 ) {
     if let Some(c) = cursor_moved.read().last() {
-        let dt = time.delta_seconds();
-
         for (camera, transform) in cameras.iter() {
             if let Some(p) = camera.viewport_to_world_2d(transform, c.position) {
                 let ray = Ray3d {
@@ -126,10 +118,7 @@ fn track_cursor(
                 };
 
                 if let Some(p) = intersect_tracking_plane(&ray) {
-                    for (mut t, mut v) in query.iter_mut() {
-                        if dt != 0.0 {
-                            v.0 = (p - t.translation) / dt;
-                        }
+                    for mut t in query.iter_mut() {
                         t.translation = p;
                     }
                 }
@@ -140,7 +129,6 @@ fn track_cursor(
     }
 }
 
-#[allow(unused)]
 fn intersect_tracking_plane(ray: &Ray3d) -> Option<Vec3> {
     let dotn = Vec3::Y.dot(*ray.direction);
     if dotn == 0.0 {
@@ -150,20 +138,3 @@ fn intersect_tracking_plane(ray: &Ray3d) -> Option<Vec3> {
         Some(ray.origin + ray.direction * t)
     }
 }
-
-/// Update dynamics object based on the tracking object's position and velocity.
-fn update_dynamic(
-    time: Res<Time>,
-    tracking: Query<(&Transform, &Velocity), With<Tracking>>,
-    mut dynamic: Query<(&mut Transform, &mut Dynamics), Without<Tracking>>,
-) {
-    // In this example there is only one.
-    if let Some((t0, v)) = tracking.iter().next() {
-        for (mut t, mut d) in dynamic.iter_mut() {
-            t.translation = d
-                .state
-                .update(time.delta_seconds(), t0.translation, Some(v.0))
-                + DYNAMIC_OFFSET;
-        }
-    }
-}
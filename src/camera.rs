@@ -0,0 +1,75 @@
+//! A dynamics-driven third-person follow camera.
+
+use bevy::{prelude::*, transform::TransformSystem};
+
+use crate::{SecondOrderDynamics, SecondOrderRotation};
+
+/// A cinematic follow camera: smooths toward a tracked entity using independently
+/// tunable dynamics for the eye position and the aim, so position lag and aim lag
+/// can be tuned separately (and each shows off its own stability branch — critically
+/// damped vs. overshoot — directly in camera motion).
+#[derive(Component)]
+pub struct DynamicCamera {
+    pub subject: Entity,
+    /// Boom offset applied in the subject's local frame before smoothing.
+    pub boom: Vec3,
+    pub eye: SecondOrderDynamics<Vec3>,
+    pub aim: SecondOrderRotation,
+}
+
+impl DynamicCamera {
+    pub fn new(
+        subject: Entity,
+        boom: Vec3,
+        eye: SecondOrderDynamics<Vec3>,
+        aim: SecondOrderRotation,
+    ) -> Self {
+        Self {
+            subject,
+            boom,
+            eye,
+            aim,
+        }
+    }
+}
+
+pub struct DynamicCameraPlugin;
+
+impl Plugin for DynamicCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            update_dynamic_camera.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+fn update_dynamic_camera(
+    time: Res<Time>,
+    subjects: Query<&GlobalTransform>,
+    mut cameras: Query<(&mut Transform, &mut DynamicCamera)>,
+) {
+    let dt = time.delta_seconds();
+    if dt == 0.0 {
+        return;
+    }
+
+    for (mut transform, mut camera) in cameras.iter_mut() {
+        let Ok(subject) = subjects.get(camera.subject) else {
+            continue;
+        };
+
+        let boom = subject.transform_point(camera.boom);
+        let eye = camera.eye.update(dt, boom, None);
+
+        // Aim at the subject from the smoothed eye position, not the subject's own
+        // orientation, so the camera keeps looking at it regardless of which way it
+        // is facing.
+        let aim = Transform::from_translation(eye)
+            .looking_at(subject.translation(), Vec3::Y)
+            .rotation;
+
+        transform.translation = eye;
+        transform.rotation = camera.aim.update(dt, aim, None);
+    }
+}
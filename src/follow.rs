@@ -0,0 +1,133 @@
+//! A drop-in "follow" behavior so examples (and users) don't have to hand-write an
+//! update system per followed entity: read a target's position, call
+//! [`SecondOrderDynamics::update`], write the result to a `Transform`.
+
+use bevy::prelude::*;
+
+use crate::{SecondOrderDynamics, SecondOrderRotation};
+
+/// Where a [`Follow`] component gets its target position (and, for
+/// [`Entity`](FollowTarget::Entity), its rotation) from.
+#[derive(Clone, Copy)]
+pub enum FollowTarget {
+    /// Track another entity's `GlobalTransform`.
+    Entity(Entity),
+    /// Track a fixed point in world space.
+    Fixed(Vec3),
+    /// Track the cursor, projected through `camera` onto the horizontal plane at
+    /// `plane_y`.
+    Cursor { camera: Entity, plane_y: f32 },
+}
+
+/// Smooths a `Transform` toward a [`FollowTarget`] using second-order dynamics.
+/// Registered by [`FollowPlugin`], which resolves the target, estimates its
+/// velocity, and writes the smoothed result back every frame.
+#[derive(Component)]
+pub struct Follow {
+    pub target: FollowTarget,
+    pub position: SecondOrderDynamics<Vec3>,
+    /// Rotation dynamics, driven by the target's rotation. Only used when `target`
+    /// is [`FollowTarget::Entity`].
+    pub rotation: Option<SecondOrderRotation>,
+    /// Applied to the smoothed position after the dynamics update, in world space.
+    pub offset: Vec3,
+}
+
+impl Follow {
+    pub fn new(target: FollowTarget, position: SecondOrderDynamics<Vec3>) -> Self {
+        Self {
+            target,
+            position,
+            rotation: None,
+            offset: Vec3::ZERO,
+        }
+    }
+
+    pub fn with_rotation(mut self, rotation: SecondOrderRotation) -> Self {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+pub struct FollowPlugin;
+
+impl Plugin for FollowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_follow);
+    }
+}
+
+fn update_follow(
+    time: Res<Time>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut last_cursor: Local<Option<Vec2>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    targets: Query<&GlobalTransform>,
+    mut followers: Query<(&mut Transform, &mut Follow)>,
+) {
+    // Keep the last known cursor position so cursor followers keep settling on
+    // frames where the cursor doesn't move.
+    if let Some(m) = cursor_moved.read().last() {
+        *last_cursor = Some(m.position);
+    }
+
+    let dt = time.delta_seconds();
+    if dt == 0.0 {
+        return;
+    }
+
+    let cursor = *last_cursor;
+
+    for (mut transform, mut follow) in followers.iter_mut() {
+        let target_pos = match follow.target {
+            FollowTarget::Entity(entity) => targets.get(entity).ok().map(|t| t.translation()),
+            FollowTarget::Fixed(p) => Some(p),
+            FollowTarget::Cursor { camera, plane_y } => cursor
+                .and_then(|cursor| cameras.get(camera).ok().map(|c| (cursor, c)))
+                .and_then(|(cursor, (camera, camera_transform))| {
+                    cursor_on_plane(camera, camera_transform, cursor, plane_y)
+                }),
+        };
+
+        let Some(target_pos) = target_pos else {
+            continue;
+        };
+
+        transform.translation = follow.position.update(dt, target_pos, None) + follow.offset;
+
+        if let FollowTarget::Entity(entity) = follow.target {
+            if let (Some(rotation), Ok(target_transform)) =
+                (follow.rotation.as_mut(), targets.get(entity))
+            {
+                transform.rotation = rotation.update(dt, target_transform.rotation(), None);
+            }
+        }
+    }
+}
+
+/// Projects a viewport cursor position into world space through `camera`, onto the
+/// horizontal plane at `plane_y`.
+fn cursor_on_plane(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    cursor: Vec2,
+    plane_y: f32,
+) -> Option<Vec3> {
+    let p = camera.viewport_to_world_2d(camera_transform, cursor)?;
+    let ray = Ray3d {
+        origin: p.extend(camera_transform.translation().z),
+        direction: Direction3d::new(camera_transform.forward()).ok()?,
+    };
+
+    let dotn = Vec3::Y.dot(*ray.direction);
+    if dotn == 0.0 {
+        return None;
+    }
+    let t = -((Vec3::Y.dot(ray.origin) - plane_y) / dotn);
+    Some(ray.origin + ray.direction * t)
+}
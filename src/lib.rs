@@ -1,6 +1,16 @@
 use std::f32::consts::{PI, TAU};
 use std::ops::*;
 
+use glam::{Quat, Vec3};
+
+mod camera;
+mod follow;
+mod profile;
+
+pub use camera::{DynamicCamera, DynamicCameraPlugin};
+pub use follow::{Follow, FollowPlugin, FollowTarget};
+pub use profile::{DynamicsProfile, DynamicsProfileHandle, DynamicsProfileLoader, DynamicsProfilePlugin};
+
 #[derive(Debug, Default)]
 pub struct SecondOrderDynamics<T> {
     // Previous input.
@@ -8,15 +18,36 @@ pub struct SecondOrderDynamics<T> {
     // State variables.
     pub y: T,
     yd: T,
+    // f/ζ/r inputs, kept around (rather than just the constants derived from them)
+    // so a UI can read them back and rebuild the response after a tweak.
+    pub f: f32,
+    pub z: f32,
+    pub r: f32,
     // Computed constants.
     w: f32,
-    z: f32,
     d: f32,
     k1: f32,
     k2: f32,
     k3: f32,
 }
 
+// Stable k1/k2 selection shared by `SecondOrderDynamics` and `SecondOrderRotation`:
+// clamp k2 when the system is slow enough to jitter, or use pole matching when it's
+// fast enough that the naive integration would go unstable.
+fn stable_k1_k2(w: f32, z: f32, d: f32, k1: f32, k2: f32, t: f32) -> (f32, f32) {
+    if w * t < z {
+        // clamp k2 to guarantee stability without jitter
+        (k1, k2.max(t * t / 2.0 + t * k1 / 2.0).max(t * k1))
+    } else {
+        // use pole matching when the system is very fast
+        let t1 = (-z * w * t).exp();
+        let alpha = 2.0 * t1 * if z <= 1.0 { (t * d).cos() } else { (t * d).cosh() };
+        let beta = t1 * t1;
+        let t2 = t / (1.0 + beta - alpha);
+        ((1.0 - beta) * t2, t * t2)
+    }
+}
+
 impl<T> SecondOrderDynamics<T>
 where
     T: Default
@@ -32,8 +63,10 @@ where
         let d = w * (z * z - 1.0).abs().sqrt();
 
         Self {
-            w,
+            f,
             z,
+            r,
+            w,
             d,
             k1: z / (PI * f),
             k2: 1.0 / (w * w),
@@ -44,44 +77,141 @@ where
         }
     }
 
+    /// The current angular/linear velocity state, e.g. for a UI to preserve across
+    /// a tuning change via [`Self::set_state`].
+    pub fn velocity(&self) -> T {
+        self.yd
+    }
+
+    /// Seeds the state from external ground truth, e.g. a physics body's actual
+    /// transform and linear velocity, so a "previous velocity" pattern can keep the
+    /// dynamics in sync when something else owns the true position.
+    pub fn set_state(&mut self, y: T, yd: T) {
+        self.y = y;
+        self.yd = yd;
+    }
+
+    // estimate velocity from the change in input since the last call
+    fn estimate_velocity(&mut self, t: f32, x: T) -> T {
+        assert!(t != 0.0);
+        let xd = (x - self.xp) / t;
+        self.xp = x;
+        xd
+    }
+
     pub fn update(&mut self, t: f32, x: T, xd: Option<T>) -> T {
-        // estimate velocity
+        let xd = xd.unwrap_or_else(|| self.estimate_velocity(t, x));
+        let (k1, k2) = stable_k1_k2(self.w, self.z, self.d, self.k1, self.k2, t);
+
+        // integrate position by velocity
+        self.y = self.y + self.yd * t;
+
+        // integrate velocity by acceleration
+        self.yd += (x + xd * self.k3 - self.y - self.yd * k1) * t / k2;
+
+        self.y
+    }
+
+    /// The instantaneous acceleration the second-order response wants, without
+    /// advancing `y`/`yd`. Multiply by mass and apply as a force/impulse to drive a
+    /// rigid body through a physics solver instead of overwriting its transform,
+    /// which fights the solver and skips collisions.
+    pub fn acceleration(&mut self, t: f32, x: T, xd: Option<T>) -> T {
+        let xd = xd.unwrap_or_else(|| self.estimate_velocity(t, x));
+        let (k1, k2) = stable_k1_k2(self.w, self.z, self.d, self.k1, self.k2, t);
+
+        (x + xd * self.k3 - self.y - self.yd * k1) / k2
+    }
+}
+
+/// Second-order dynamics for rotations.
+///
+/// `SecondOrderDynamics<T>` can't be used for `Quat`: its bounds describe a linear
+/// vector space, and naive component-wise interpolation of a quaternion drifts off
+/// the unit sphere and can take the long way around. This sibling keeps the same
+/// f/ζ/r response, but the state is a unit `Quat` plus an angular velocity that
+/// lives in the tangent space (a rotation vector), and the error fed to the
+/// integrator each step is the shortest-arc difference between target and state.
+#[derive(Debug)]
+pub struct SecondOrderRotation {
+    // Previous input, used to estimate angular velocity when none is given.
+    xp: Quat,
+    // State variables.
+    pub y: Quat,
+    yd: Vec3,
+    // f/ζ/r inputs, kept around for the same reason as
+    // [`SecondOrderDynamics`]'s — so a UI can read them back and rebuild the
+    // response after a tweak.
+    pub f: f32,
+    pub z: f32,
+    pub r: f32,
+    // Computed constants.
+    w: f32,
+    d: f32,
+    k1: f32,
+    k2: f32,
+    k3: f32,
+}
+
+impl SecondOrderRotation {
+    pub fn new(f: f32, z: f32, r: f32, x0: Quat) -> Self {
+        let w = TAU * f;
+        let d = w * (z * z - 1.0).abs().sqrt();
+
+        Self {
+            f,
+            z,
+            r,
+            w,
+            d,
+            k1: z / (PI * f),
+            k2: 1.0 / (w * w),
+            k3: r * z / w,
+            xp: x0,
+            y: x0,
+            yd: Vec3::ZERO,
+        }
+    }
+
+    /// The current angular velocity state, e.g. for a UI to preserve across a
+    /// tuning change via [`Self::set_state`].
+    pub fn angular_velocity(&self) -> Vec3 {
+        self.yd
+    }
+
+    /// Seeds the state from external ground truth, mirroring
+    /// [`SecondOrderDynamics::set_state`].
+    pub fn set_state(&mut self, y: Quat, yd: Vec3) {
+        self.y = y;
+        self.yd = yd;
+    }
+
+    pub fn update(&mut self, t: f32, x: Quat, xd: Option<Vec3>) -> Quat {
+        // estimate angular velocity
         let xd = xd.unwrap_or_else(|| {
             assert!(t != 0.0);
-            let xd = (x - self.xp) / t;
+            let xd = (x * self.xp.inverse()).to_scaled_axis() / t;
             self.xp = x;
             xd
         });
 
-        // compute stable k1/k2
-        let (k1, k2) = if self.w * t < self.z {
-            // clamp k2 to guarantee stability without jitter
-            (
-                self.k1,
-                self.k2
-                    .max(t * t / 2.0 + t * self.k1 / 2.0)
-                    .max(t * self.k1),
-            )
-        } else {
-            // use pole matching when the system is very fast
-            let t1 = (-self.z * self.w * t).exp();
-            let alpha = 2.0
-                * t1
-                * if self.z <= 1.0 {
-                    (t * self.d).cos()
-                } else {
-                    (t * self.d).cosh()
-                };
-            let beta = t1 * t1;
-            let t2 = t / (1.0 + beta - alpha);
-            ((1.0 - beta) * t2, t * t2)
-        };
+        let (k1, k2) = stable_k1_k2(self.w, self.z, self.d, self.k1, self.k2, t);
 
-        // integrate position by velocity
-        self.y = self.y + self.yd * t;
+        // shortest-arc error between the target and the current orientation, as a
+        // rotation vector in the tangent space
+        let mut e = (x * self.y.inverse()).normalize();
+        if e.w < 0.0 {
+            // take the short way around instead of spinning the long way
+            e = -e;
+        }
+        let (axis, angle) = e.to_axis_angle();
+        let err = axis * angle;
 
-        // integrate velocity by acceleration
-        self.yd += (x + xd * self.k3 - self.y - self.yd * k1) * t / k2;
+        // integrate angular velocity by angular acceleration
+        self.yd += (err + xd * self.k3 - self.yd * k1) * t / k2;
+
+        // integrate orientation by angular velocity
+        self.y = (Quat::from_scaled_axis(self.yd * t) * self.y).normalize();
 
         self.y
     }
@@ -89,7 +219,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::SecondOrderDynamics;
+    use super::{Quat, SecondOrderDynamics, SecondOrderRotation};
 
     #[test]
     fn it_works() {
@@ -100,4 +230,16 @@ mod tests {
         }
         assert!(y >= 1.0);
     }
+
+    #[test]
+    fn rotation_settles_on_target() {
+        let target = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let mut dynamics = SecondOrderRotation::new(2.0, 1.0, 0.0, Quat::IDENTITY);
+        let mut y = Quat::IDENTITY;
+        for _ in 0..1000 {
+            y = dynamics.update(0.01, target, None);
+            assert!((y.length() - 1.0).abs() < 1e-4);
+        }
+        assert!(y.angle_between(target) < 0.01);
+    }
 }
@@ -0,0 +1,104 @@
+//! A saveable, hot-reloadable f/ζ/r tuning preset for [`SecondOrderDynamics`] and
+//! [`SecondOrderRotation`]. The egui sliders (see the examples) are great for live
+//! tweaking, but the values can't be saved or shared; a `.dynamics.ron` file can.
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{Follow, SecondOrderDynamics, SecondOrderRotation};
+
+/// A named f/ζ/r preset, e.g. "snappy", "floaty", "overshoot".
+#[derive(Asset, TypePath, Debug, Clone, Copy, Deserialize)]
+pub struct DynamicsProfile {
+    pub f: f32,
+    pub z: f32,
+    pub r: f32,
+}
+
+#[derive(Default)]
+pub struct DynamicsProfileLoader;
+
+#[derive(Debug, Error)]
+pub enum DynamicsProfileError {
+    #[error("could not read dynamics profile: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse dynamics profile: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for DynamicsProfileLoader {
+    type Asset = DynamicsProfile;
+    type Settings = ();
+    type Error = DynamicsProfileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dynamics.ron"]
+    }
+}
+
+/// Points a [`Follow`] at a hot-reloadable [`DynamicsProfile`]. When the asset
+/// changes on disk, [`DynamicsProfilePlugin`] rebuilds the `Follow`'s dynamics with
+/// the new f/ζ/r, keeping the current position/orientation so the retune doesn't
+/// pop.
+#[derive(Component)]
+pub struct DynamicsProfileHandle(pub Handle<DynamicsProfile>);
+
+pub struct DynamicsProfilePlugin;
+
+impl Plugin for DynamicsProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DynamicsProfile>()
+            .init_asset_loader::<DynamicsProfileLoader>()
+            .add_systems(Update, reload_dynamics_profile);
+    }
+}
+
+fn reload_dynamics_profile(
+    mut events: EventReader<AssetEvent<DynamicsProfile>>,
+    profiles: Res<Assets<DynamicsProfile>>,
+    mut followers: Query<(&DynamicsProfileHandle, &mut Follow)>,
+) {
+    for event in events.read() {
+        let (AssetEvent::Modified { id } | AssetEvent::LoadedWithDependencies { id }) = event
+        else {
+            continue;
+        };
+
+        let Some(profile) = profiles.get(*id) else {
+            continue;
+        };
+
+        for (handle, mut follow) in &mut followers {
+            if handle.0.id() != *id {
+                continue;
+            }
+
+            let (y, yd) = (follow.position.y, follow.position.velocity());
+            let mut position = SecondOrderDynamics::new(profile.f, profile.z, profile.r, y);
+            position.set_state(y, yd);
+            follow.position = position;
+
+            if let Some(rotation) = follow.rotation.as_ref() {
+                let (y, yd) = (rotation.y, rotation.angular_velocity());
+                let mut rotation = SecondOrderRotation::new(profile.f, profile.z, profile.r, y);
+                rotation.set_state(y, yd);
+                follow.rotation = Some(rotation);
+            }
+        }
+    }
+}